@@ -0,0 +1,169 @@
+use std::time::Duration;
+use std::vec;
+
+use super::RendTri;
+use super::Shape;
+
+/// An easing curve: maps a linear `[0, 1]` input to an eased output that
+/// starts at `0.0` and ends at `1.0`. Curves such as `ease::back_in_out` leave
+/// `[0, 1]` in between.
+pub type Easing = fn(f32) -> f32;
+
+pub mod ease {
+    /// No easing; output equals input.
+    pub fn linear(t: f32) -> f32 {
+        t
+    }
+
+    /// Accelerate in, decelerate out.
+    pub fn quad_in_out(t: f32) -> f32 {
+        if t < 0.5 {
+            2.0 * t * t
+        } else {
+            1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+        }
+    }
+
+    /// Accelerate in, decelerate out, with a steeper curve than `quad_in_out`.
+    pub fn cubic_in_out(t: f32) -> f32 {
+        if t < 0.5 {
+            4.0 * t * t * t
+        } else {
+            1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+        }
+    }
+
+    /// A smooth, sinusoidal ease in and out.
+    pub fn sine_in_out(t: f32) -> f32 {
+        -((::std::f32::consts::PI * t).cos() - 1.0) / 2.0
+    }
+
+    /// Overshoots past `1.0` before settling back, like a spring.
+    pub fn back_in_out(t: f32) -> f32 {
+        let c1 = 1.70158;
+        let c2 = c1 * 1.525;
+        if t < 0.5 {
+            ((2.0 * t).powi(2) * ((c2 + 1.0) * 2.0 * t - c2)) / 2.0
+        } else {
+            (((2.0 * t - 2.0).powi(2) * ((c2 + 1.0) * (t * 2.0 - 2.0) + c2)) + 2.0) / 2.0
+        }
+    }
+}
+
+/// How an `Animation`'s normalized `delta` behaves once it reaches the end of
+/// its duration.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Repeat {
+    /// Run once and clamp at `1.0`.
+    Once,
+    /// Wrap back around to `0.0` and continue.
+    Repeat,
+    /// Reflect back toward `0.0` instead of wrapping, alternating direction
+    /// every period.
+    PingPong,
+}
+
+/// A time-driven, normalized `[0, 1]` progress value with an easing curve and
+/// a repeat mode, used to drive transforms over time.
+///
+/// `Animation` owns the easing/repeat math, not the clock: it still takes an
+/// `elapsed: Duration` from the caller (there's no frame clock reachable from
+/// this module), so callers keep their own `Instant` exactly as the flower
+/// example's manual `start.elapsed()` did. What moves into `Animation` is the
+/// part that used to be re-derived by hand at each call site: mapping
+/// elapsed time to an eased, wrapped/ping-ponged `[0, 1]` delta.
+///
+/// ## Example
+/// ```rust,no_run
+/// use nest::*;
+/// use std::time::Duration;
+/// let spin = Animation::new(Duration::from_secs(2), ease::sine_in_out).pingpong();
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Animation {
+    period: Duration,
+    easing: Easing,
+    repeat: Repeat,
+}
+
+impl Animation {
+    /// Create an animation lasting `period`, eased by `easing`, that runs
+    /// once and clamps at the end by default.
+    pub fn new(period: Duration, easing: Easing) -> Animation {
+        Animation {
+            period: period,
+            easing: easing,
+            repeat: Repeat::Once,
+        }
+    }
+
+    /// Loop the animation, wrapping back to the start once it finishes.
+    pub fn repeat(mut self) -> Animation {
+        self.repeat = Repeat::Repeat;
+        self
+    }
+
+    /// Loop the animation, reflecting back and forth instead of wrapping.
+    pub fn pingpong(mut self) -> Animation {
+        self.repeat = Repeat::PingPong;
+        self
+    }
+
+    /// Compute the eased `delta` for `elapsed` time since the animation
+    /// started: the progress is wrapped into `[0, 1]` and then passed through
+    /// the easing curve.
+    pub fn delta(&self, elapsed: Duration) -> f32 {
+        let period = self.period.as_secs_f32().max(::std::f32::EPSILON);
+        let raw = elapsed.as_secs_f32() / period;
+
+        let wrapped = match self.repeat {
+            Repeat::Once => raw.min(1.0),
+            Repeat::Repeat => raw % 1.0,
+            Repeat::PingPong => {
+                let cycle = raw % 2.0;
+                if cycle <= 1.0 {
+                    cycle
+                } else {
+                    2.0 - cycle
+                }
+            }
+        };
+
+        (self.easing)(wrapped)
+    }
+}
+
+/// A shape driven by an `Animation`, recomputed every time it's iterated by
+/// applying `transform` with the animation's current `delta`.
+#[derive(Clone)]
+pub struct Animate<S, F> {
+    shape: S,
+    animation: Animation,
+    elapsed: Duration,
+    transform: F,
+}
+
+impl<S, F> Animate<S, F> {
+    pub(crate) fn new(shape: S, animation: Animation, elapsed: Duration, transform: F) -> Animate<S, F> {
+        Animate {
+            shape: shape,
+            animation: animation,
+            elapsed: elapsed,
+            transform: transform,
+        }
+    }
+}
+
+impl<S, T, F> IntoIterator for Animate<S, F>
+where
+    S: Shape + Clone,
+    T: Shape,
+    F: Fn(S, f32) -> T,
+{
+    type Item = RendTri;
+    type IntoIter = vec::IntoIter<RendTri>;
+    fn into_iter(self) -> Self::IntoIter {
+        let delta = self.animation.delta(self.elapsed);
+        (self.transform)(self.shape, delta).into_iter().collect::<Vec<_>>().into_iter()
+    }
+}
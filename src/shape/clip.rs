@@ -0,0 +1,272 @@
+use std::iter;
+use std::vec;
+
+use super::{RendTri, Rect, Shape, Tri};
+
+/// Placeholder mask used when a `Clip` restricts only via a rectangle and
+/// has no arbitrary mask shape to speak of.
+#[derive(Copy, Clone, Debug)]
+pub struct NoMask;
+
+impl IntoIterator for NoMask {
+    type Item = RendTri;
+    type IntoIter = iter::Empty<RendTri>;
+    fn into_iter(self) -> Self::IntoIter {
+        iter::empty()
+    }
+}
+
+/// Restricts where a shape's triangles are rendered, either to a rectangular
+/// region or to the exact area covered by an arbitrary mask shape.
+///
+/// Clipping is done on the CPU against the triangles themselves, rather than
+/// through a GPU scissor/stencil test, so it has no dependency on the draw
+/// pipeline and clips correctly regardless of how `RendTri`s eventually reach
+/// the screen. The rectangular fast path clips each content triangle against
+/// the rect's four edges with one Sutherland-Hodgman pass. The arbitrary-mask
+/// path clips each content triangle against every triangle the mask shape is
+/// already made of (its own `IntoIterator<Item = RendTri>` triangulation) and
+/// keeps the union of the results, so a circular, star-shaped, or otherwise
+/// concave mask clips to its actual silhouette rather than its bounding box.
+#[derive(Clone)]
+pub struct Clip<S, M> {
+    content: S,
+    mask: ClipMask<M>,
+}
+
+/// The region a `Clip` restricts drawing to.
+#[derive(Clone)]
+enum ClipMask<M> {
+    /// The rectangular fast path.
+    Rect(Rect),
+    /// An arbitrary shape, clipped to its own triangulated silhouette.
+    Shape(M),
+}
+
+impl<S: Shape> Clip<S, NoMask> {
+    /// Clip `content` to a rectangle.
+    pub fn rect(content: S, rect: Rect) -> Clip<S, NoMask> {
+        Clip {
+            content: content,
+            mask: ClipMask::Rect(rect),
+        }
+    }
+}
+
+impl<S: Shape, M: Shape> Clip<S, M> {
+    /// Clip `content` to the silhouette of an arbitrary `mask` shape.
+    pub fn mask(content: S, mask: M) -> Clip<S, M> {
+        Clip {
+            content: content,
+            mask: ClipMask::Shape(mask),
+        }
+    }
+}
+
+impl<S: Shape, M: Shape> IntoIterator for Clip<S, M> {
+    type Item = RendTri;
+    type IntoIter = vec::IntoIter<RendTri>;
+    fn into_iter(self) -> Self::IntoIter {
+        match self.mask {
+            ClipMask::Rect(rect) => {
+                let verts = rect_verts(rect);
+                self.content
+                    .into_iter()
+                    .flat_map(|tri| clip_to_convex(tri, &verts))
+                    .collect::<Vec<_>>()
+                    .into_iter()
+            }
+            ClipMask::Shape(mask) => {
+                // Every triangle the mask triangulates to is itself convex
+                // (a single triangle), so clipping against each one and
+                // keeping every non-empty result gives the union of the
+                // content clipped to the mask's whole (possibly concave)
+                // silhouette.
+                let mask_tris: Vec<[[f32; 2]; 3]> = mask
+                    .into_iter()
+                    .map(|tri| tri.tri.positions.0)
+                    .collect();
+                self.content
+                    .into_iter()
+                    .flat_map(|tri| {
+                        mask_tris
+                            .iter()
+                            .flat_map(|verts| clip_to_convex(tri.clone(), verts))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+                    .into_iter()
+            }
+        }
+    }
+}
+
+/// The four corners of `rect`, in counter-clockwise order.
+fn rect_verts(rect: Rect) -> [[f32; 2]; 4] {
+    let min = [rect.0[0].min(rect.1[0]), rect.0[1].min(rect.1[1])];
+    let max = [rect.0[0].max(rect.1[0]), rect.0[1].max(rect.1[1])];
+    [min, [max[0], min[1]], max, [min[0], max[1]]]
+}
+
+/// A triangle vertex carrying every attribute that needs to be interpolated
+/// when a clip edge cuts through an edge of the triangle being clipped.
+#[derive(Copy, Clone)]
+struct ClipVertex {
+    pos: [f32; 2],
+    texcoord: [f32; 2],
+    color: [f32; 4],
+}
+
+fn lerp_vertex(a: ClipVertex, b: ClipVertex, t: f32) -> ClipVertex {
+    let lerp2 = |a: [f32; 2], b: [f32; 2]| [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t];
+    let lerp4 = |a: [f32; 4], b: [f32; 4]| {
+        [
+            a[0] + (b[0] - a[0]) * t,
+            a[1] + (b[1] - a[1]) * t,
+            a[2] + (b[2] - a[2]) * t,
+            a[3] + (b[3] - a[3]) * t,
+        ]
+    };
+    ClipVertex {
+        pos: lerp2(a.pos, b.pos),
+        texcoord: lerp2(a.texcoord, b.texcoord),
+        color: lerp4(a.color, b.color),
+    }
+}
+
+fn cross(o: [f32; 2], a: [f32; 2], b: [f32; 2]) -> f32 {
+    (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+}
+
+fn signed_area(verts: &[[f32; 2]]) -> f32 {
+    let mut area = 0.0;
+    for i in 0..verts.len() {
+        let a = verts[i];
+        let b = verts[(i + 1) % verts.len()];
+        area += a[0] * b[1] - b[0] * a[1];
+    }
+    area * 0.5
+}
+
+/// Clip a polygon against the single half-plane to the left of the directed
+/// edge `edge_a -> edge_b`. One Sutherland-Hodgman pass.
+fn clip_edge(poly: Vec<ClipVertex>, edge_a: [f32; 2], edge_b: [f32; 2]) -> Vec<ClipVertex> {
+    if poly.is_empty() {
+        return poly;
+    }
+    let inside = |v: &ClipVertex| cross(edge_a, edge_b, v.pos) >= 0.0;
+
+    let n = poly.len();
+    let mut out = Vec::with_capacity(n + 1);
+    for i in 0..n {
+        let cur = poly[i];
+        let prev = poly[(i + n - 1) % n];
+        let cur_in = inside(&cur);
+        let prev_in = inside(&prev);
+        if cur_in != prev_in {
+            // Distance of prev/cur from the edge line, used to find where
+            // the segment between them crosses it.
+            let d_prev = cross(edge_a, edge_b, prev.pos);
+            let d_cur = cross(edge_a, edge_b, cur.pos);
+            let t = d_prev / (d_prev - d_cur);
+            out.push(lerp_vertex(prev, cur, t));
+        }
+        if cur_in {
+            out.push(cur);
+        }
+    }
+    out
+}
+
+/// Clip a single triangle against an arbitrary convex polygon (given in
+/// either winding order), returning zero or more triangles. A triangle
+/// clipped by a convex polygon with `n` vertices stays convex with up to
+/// `3 + n` vertices, which are fan-triangulated back into `RendTri`s.
+fn clip_to_convex(tri: RendTri, verts: &[[f32; 2]]) -> Vec<RendTri> {
+    let mut verts = verts.to_vec();
+    if signed_area(&verts) < 0.0 {
+        verts.reverse();
+    }
+
+    let positions = tri.tri.positions.0;
+    let texcoords = tri.tri.texcoords.0;
+    let colors = tri.tri.colors.0;
+    let texture = tri.texture.clone();
+
+    let mut poly: Vec<ClipVertex> = (0..3)
+        .map(|i| {
+            ClipVertex {
+                pos: positions[i],
+                texcoord: texcoords[i],
+                color: colors[i],
+            }
+        })
+        .collect();
+
+    for i in 0..verts.len() {
+        let a = verts[i];
+        let b = verts[(i + 1) % verts.len()];
+        poly = clip_edge(poly, a, b);
+        if poly.is_empty() {
+            return Vec::new();
+        }
+    }
+
+    if poly.len() < 3 {
+        return Vec::new();
+    }
+
+    (1..poly.len() - 1)
+        .map(|i| {
+            let clipped = Tri::new_colors(
+                [poly[0].pos, poly[i].pos, poly[i + 1].pos],
+                [poly[0].texcoord, poly[i].texcoord, poly[i + 1].texcoord],
+                [poly[0].color, poly[i].color, poly[i + 1].color],
+            );
+            RendTri::from(clipped).map_texture(texture.clone())
+        })
+        .collect()
+}
+
+/// Clip a single triangle against a rectangle. Kept as a thin wrapper over
+/// `clip_to_convex` so the rectangular fast path shares the exact same
+/// clipping code as the arbitrary-mask path.
+fn clip_to_rect(tri: RendTri, rect: Rect) -> Vec<RendTri> {
+    clip_to_convex(tri, &rect_verts(rect))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flat_tri(positions: [[f32; 2]; 3]) -> RendTri {
+        Tri::new_pos(positions).into()
+    }
+
+    #[test]
+    fn clip_to_rect_fully_inside_passes_through() {
+        let tri = flat_tri([[-0.1, -0.1], [0.1, -0.1], [-0.1, 0.1]]);
+        let result = clip_to_rect(tri, Rect([-1.0, -1.0], [1.0, 1.0]));
+        assert_eq!(result.len(), 1);
+    }
+
+    #[test]
+    fn clip_to_rect_fully_outside_is_empty() {
+        let tri = flat_tri([[2.0, 2.0], [3.0, 2.0], [2.0, 3.0]]);
+        let result = clip_to_rect(tri, Rect([-1.0, -1.0], [1.0, 1.0]));
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn clip_to_rect_straddling_edge_stays_within_bounds() {
+        let tri = flat_tri([[-2.0, -2.0], [2.0, -2.0], [-2.0, 2.0]]);
+        let result = clip_to_rect(tri, Rect([-1.0, -1.0], [1.0, 1.0]));
+        assert!(!result.is_empty());
+        for rend in &result {
+            for p in &rend.tri.positions.0 {
+                assert!(p[0] >= -1.0 - 1e-4 && p[0] <= 1.0 + 1e-4);
+                assert!(p[1] >= -1.0 - 1e-4 && p[1] <= 1.0 + 1e-4);
+            }
+        }
+    }
+}
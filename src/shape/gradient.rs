@@ -0,0 +1,119 @@
+use cgm;
+use std::vec;
+
+use super::RendTri;
+
+/// A fill that can be sampled at any point in shape-space to produce a color.
+pub trait Gradient {
+    /// Sample the gradient's color at `pos`.
+    fn sample(&self, pos: cgm::Point2<f32>) -> [f32; 4];
+}
+
+/// Look up a color in a piecewise-linear ramp of `(position, color)` stops,
+/// where `position` is in `[0, 1]` and stops are assumed sorted by position.
+fn ramp(stops: &[(f32, [f32; 4])], t: f32) -> [f32; 4] {
+    if stops.is_empty() {
+        return [1.0, 1.0, 1.0, 1.0];
+    }
+    if t <= stops[0].0 {
+        return stops[0].1;
+    }
+    for window in stops.windows(2) {
+        let (a, b) = (window[0], window[1]);
+        if t <= b.0 {
+            let span = (b.0 - a.0).max(::std::f32::EPSILON);
+            let local = (t - a.0) / span;
+            return lerp_color(a.1, b.1, local);
+        }
+    }
+    stops[stops.len() - 1].1
+}
+
+fn lerp_color(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    [
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+        a[3] + (b[3] - a[3]) * t,
+    ]
+}
+
+/// A gradient that varies along a straight line from `start` to `end`,
+/// sampled by projecting each position onto that axis.
+#[derive(Clone, Debug)]
+pub struct LinearGradient {
+    pub start: [f32; 2],
+    pub end: [f32; 2],
+    pub stops: Vec<(f32, [f32; 4])>,
+}
+
+impl LinearGradient {
+    pub fn new(start: [f32; 2], end: [f32; 2], stops: Vec<(f32, [f32; 4])>) -> LinearGradient {
+        LinearGradient {
+            start: start,
+            end: end,
+            stops: stops,
+        }
+    }
+}
+
+impl Gradient for LinearGradient {
+    fn sample(&self, pos: cgm::Point2<f32>) -> [f32; 4] {
+        let axis = [self.end[0] - self.start[0], self.end[1] - self.start[1]];
+        let len_sq = (axis[0] * axis[0] + axis[1] * axis[1]).max(::std::f32::EPSILON);
+        let to_pos = [pos.x - self.start[0], pos.y - self.start[1]];
+        let t = (to_pos[0] * axis[0] + to_pos[1] * axis[1]) / len_sq;
+        ramp(&self.stops, t.max(0.0).min(1.0))
+    }
+}
+
+/// A gradient that varies radially outward from `center`, sampled by distance
+/// from the center relative to `radius`.
+#[derive(Clone, Debug)]
+pub struct RadialGradient {
+    pub center: [f32; 2],
+    pub radius: f32,
+    pub stops: Vec<(f32, [f32; 4])>,
+}
+
+impl RadialGradient {
+    pub fn new(center: [f32; 2], radius: f32, stops: Vec<(f32, [f32; 4])>) -> RadialGradient {
+        RadialGradient {
+            center: center,
+            radius: radius,
+            stops: stops,
+        }
+    }
+}
+
+impl Gradient for RadialGradient {
+    fn sample(&self, pos: cgm::Point2<f32>) -> [f32; 4] {
+        let dx = pos.x - self.center[0];
+        let dy = pos.y - self.center[1];
+        let t = (dx * dx + dy * dy).sqrt() / self.radius.max(::std::f32::EPSILON);
+        ramp(&self.stops, t.max(0.0).min(1.0))
+    }
+}
+
+/// A shape recolored by sampling a `Gradient` at each of its vertices.
+#[derive(Clone)]
+pub struct Fill<S, G>(S, G);
+
+impl<S, G> Fill<S, G> {
+    pub(crate) fn new(shape: S, gradient: G) -> Fill<S, G> {
+        Fill(shape, gradient)
+    }
+}
+
+impl<S: IntoIterator<Item = RendTri>, G: Gradient> IntoIterator for Fill<S, G> {
+    type Item = RendTri;
+    type IntoIter = vec::IntoIter<RendTri>;
+    fn into_iter(self) -> Self::IntoIter {
+        let Fill(shape, gradient) = self;
+        shape
+            .into_iter()
+            .map(|tri| tri.map_colors(|pos, _| gradient.sample(pos)))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
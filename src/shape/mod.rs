@@ -9,10 +9,26 @@ use std::iter::{Chain, Once, once};
 mod translate;
 mod rotate;
 mod combine;
+mod text;
+mod polygon;
+mod primitives;
+mod gradient;
+mod animation;
+mod clip;
+mod particles;
 
 pub use self::translate::*;
 pub use self::rotate::*;
 pub use self::combine::*;
+pub use self::text::*;
+pub use self::polygon::*;
+pub use self::primitives::*;
+pub use self::gradient::*;
+pub use self::animation::*;
+pub use self::clip::*;
+pub use self::particles::*;
+
+use std::time::Duration;
 
 /// Trait for structs to be drawn with `Frame::draw`
 pub trait Shape: IntoIterator<Item = RendTri> {
@@ -45,11 +61,78 @@ pub trait Shape: IntoIterator<Item = RendTri> {
     fn rotate(&self, angle: f32) -> Rotate<Self> where Self: Clone {
         Rotate::new(self.clone(), angle)
     }
+
+    /// Recolor a shape's vertices by sampling a gradient at each vertex's position.
+    ///
+    /// ## Example
+    /// ```rust,no_run
+    /// use nest::*;
+    /// let mut app = Window::new("Example", 640, 480).unwrap();
+    /// let gradient = LinearGradient::new([-0.5, 0.0], [0.5, 0.0], vec![(0.0, [1.0, 0.0, 0.0, 1.0]), (1.0, [0.0, 0.0, 1.0, 1.0])]);
+    /// app.draw(Rect([-0.5, -0.5], [0.5, 0.5]).fill(gradient));
+    /// ```
+    fn fill<G: Gradient>(&self, gradient: G) -> Fill<Self, G> where Self: Clone {
+        Fill::new(self.clone(), gradient)
+    }
+
+    /// Drive a transform from an `Animation`'s current, eased progress.
+    ///
+    /// This module has no access to the `Window`'s frame clock, so the
+    /// caller still has to track an `Instant` and pass its `.elapsed()` in
+    /// as `elapsed` every frame, same as the flower example's manual
+    /// `start.elapsed()` rotation did. What `Animation`/`animate` take over
+    /// is the part that used to be hand-rolled at every call site: turning
+    /// elapsed time into a normalized, eased `[0, 1]` progress value with
+    /// repeat/ping-pong wrapping, so that logic is written once instead of
+    /// per shape. `transform` is applied to a clone of this shape with that
+    /// `delta` each time the result is iterated.
+    ///
+    /// ## Example
+    /// ```rust,no_run
+    /// use nest::*;
+    /// use std::f32::consts::PI;
+    /// use std::time::{Duration, Instant};
+    /// let mut app = Window::new("Example", 640, 480).unwrap();
+    /// let shape = Rect([-0.5, -0.5], [0.5, 0.5]);
+    /// let spin = Animation::new(Duration::from_secs(2), ease::linear).repeat();
+    /// let start = Instant::now();
+    /// app.draw(shape.animate(spin, start.elapsed(), |s, delta| s.rotate(delta * 2.0 * PI)));
+    /// ```
+    fn animate<T: Shape, F: Fn(Self, f32) -> T>(
+        &self,
+        animation: Animation,
+        elapsed: Duration,
+        transform: F,
+    ) -> Animate<Self, F>
+    where
+        Self: Clone,
+    {
+        Animate::new(self.clone(), animation, elapsed, transform)
+    }
+
+    /// Restrict where this shape is drawn to a rectangular region.
+    ///
+    /// ## Example
+    /// ```rust,no_run
+    /// use nest::*;
+    /// let mut app = Window::new("Example", 640, 480).unwrap();
+    /// app.draw(Rect([-0.5, -0.5], [0.5, 0.5]).clip(Rect([-0.2, -0.2], [0.2, 0.2])));
+    /// ```
+    fn clip(&self, rect: Rect) -> Clip<Self, NoMask> where Self: Clone {
+        Clip::rect(self.clone(), rect)
+    }
+
+    /// Restrict where this shape is drawn to the area covered by the
+    /// triangles of an arbitrary `mask` shape.
+    fn clip_mask<M: Shape>(&self, mask: M) -> Clip<Self, M> where Self: Clone {
+        Clip::mask(self.clone(), mask)
+    }
 }
 
 impl<S> Shape for S where S: IntoIterator<Item = RendTri> {}
 
 /// Renderable triangle which includes color and texture information.
+#[derive(Clone)]
 pub struct RendTri {
     pub(crate) tri: Tri,
     pub(crate) texture: Option<Rc<Texture2d>>,
@@ -68,9 +151,17 @@ impl RendTri {
         self
     }
 
+    /// Recolor each vertex independently, based on that vertex's position.
     #[inline]
-    fn map_color<F: FnMut([f32; 4]) -> [f32; 4]>(mut self, mut f: F) -> RendTri {
-        self.tri.color = f(self.tri.color);
+    fn map_colors<F: FnMut(cgm::Point2<f32>, [f32; 4]) -> [f32; 4]>(mut self, mut f: F) -> RendTri {
+        let positions = self.tri.positions;
+        self.tri.colors = Colors(
+            [
+                f(positions.0[0].into(), self.tri.colors.0[0]),
+                f(positions.0[1].into(), self.tri.colors.0[1]),
+                f(positions.0[2].into(), self.tri.colors.0[2]),
+            ],
+        );
         self
     }
 
@@ -100,6 +191,11 @@ impl Positions {
     }
 }
 
+/// Three per-vertex colors, interpolated across the triangle's face by the
+/// fragment shader the same way `Positions`/`texcoords` are.
+#[derive(Copy, Clone, Debug)]
+pub struct Colors(pub [[f32; 4]; 3]);
+
 /// A triangle primitive which enters the shader pipeline as a single vertex and is the only primitive in nest
 #[derive(Copy, Clone, Debug)]
 pub struct Tri {
@@ -107,17 +203,28 @@ pub struct Tri {
     pub positions: Positions,
     /// The three texture coordinates of the above vertices
     pub texcoords: Positions,
-    /// The color of this triangle.
-    pub color: [f32; 4],
+    /// The color of each of the three vertices above, interpolated across the face of the triangle.
+    pub colors: Colors,
 }
 
 impl Tri {
-    /// Create a new triangle with points and tex coordinates specified
+    /// Create a new triangle with points, tex coordinates, and a single color shared by all three vertices.
     #[inline]
     pub fn new<P: Into<cgm::Point2<f32>> + Copy, T: Into<cgm::Point2<f32>> + Copy, C: Into<Color>>(
         positions: [P; 3],
         texcoords: [T; 3],
         color: C,
+    ) -> Tri {
+        let color = color.into().0;
+        Tri::new_colors(positions, texcoords, [color, color, color])
+    }
+
+    /// Create a new triangle with points, tex coordinates, and a distinct color per vertex.
+    #[inline]
+    pub fn new_colors<P: Into<cgm::Point2<f32>> + Copy, T: Into<cgm::Point2<f32>> + Copy>(
+        positions: [P; 3],
+        texcoords: [T; 3],
+        colors: [[f32; 4]; 3],
     ) -> Tri {
         Tri {
             positions: Positions(
@@ -134,7 +241,7 @@ impl Tri {
                     texcoords[2].into().into(),
                 ],
             ),
-            color: color.into().0,
+            colors: Colors(colors),
         }
     }
 
@@ -153,7 +260,7 @@ impl Tri {
     }
 }
 
-implement_vertex!(Tri, positions, texcoords, color);
+implement_vertex!(Tri, positions, texcoords, colors);
 
 unsafe impl glium::vertex::Attribute for Positions {
     fn get_type() -> glium::vertex::AttributeType {
@@ -161,6 +268,12 @@ unsafe impl glium::vertex::Attribute for Positions {
     }
 }
 
+unsafe impl glium::vertex::Attribute for Colors {
+    fn get_type() -> glium::vertex::AttributeType {
+        glium::vertex::AttributeType::F32x4x3
+    }
+}
+
 /// Two points make a rectangle.
 #[derive(Copy, Clone, Debug)]
 pub struct Rect(pub [f32; 2], pub [f32; 2]);
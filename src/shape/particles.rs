@@ -0,0 +1,265 @@
+use rand;
+use rand::Rng;
+
+use std::rc::Rc;
+use std::time::Duration;
+use std::vec;
+
+use glium::texture::Texture2d;
+
+use super::{RendTri, Tri};
+
+/// A single live particle's simulated state.
+#[derive(Copy, Clone, Debug)]
+struct Particle {
+    pos: [f32; 2],
+    vel: [f32; 2],
+    age: f32,
+    lifetime: f32,
+}
+
+impl Particle {
+    fn normalized_age(&self) -> f32 {
+        (self.age / self.lifetime).min(1.0)
+    }
+}
+
+/// A uniform random range particles are spawned with.
+#[derive(Copy, Clone, Debug)]
+pub struct Range<T> {
+    pub min: T,
+    pub max: T,
+}
+
+/// `Rng::gen_range` panics if `min >= max`, but a fixed (non-random) value is
+/// a perfectly normal thing to configure an emitter with (e.g. always
+/// spawning at the same point), so fall back to `min` instead of trusting
+/// every caller to keep bounds strictly increasing.
+fn gen_range_or<R: Rng>(rng: &mut R, min: f32, max: f32) -> f32 {
+    if min < max {
+        rng.gen_range(min, max)
+    } else {
+        min
+    }
+}
+
+impl Range<f32> {
+    fn sample<R: Rng>(&self, rng: &mut R) -> f32 {
+        gen_range_or(rng, self.min, self.max)
+    }
+}
+
+impl Range<[f32; 2]> {
+    fn sample<R: Rng>(&self, rng: &mut R) -> [f32; 2] {
+        [
+            gen_range_or(rng, self.min[0], self.max[0]),
+            gen_range_or(rng, self.min[1], self.max[1]),
+        ]
+    }
+}
+
+/// A GPU-friendly particle emitter: continuously spawns particles at a fixed
+/// rate, simulates them each update, and retires them once they age past
+/// their lifetime.
+///
+/// `Emitter` implements `Shape`, emitting a small colored (and optionally
+/// textured) quad per live particle, so it draws through the same textured
+/// triangle pipeline as every other shape.
+pub struct Emitter {
+    /// Particles spawned per second.
+    pub rate: f32,
+    /// How long a particle lives, in seconds.
+    pub lifetime: Range<f32>,
+    /// Where particles are spawned.
+    pub position: Range<[f32; 2]>,
+    /// The initial velocity particles are spawned with.
+    pub velocity: Range<[f32; 2]>,
+    /// Constant acceleration applied every update (gravity, wind, ...).
+    pub gravity: [f32; 2],
+    /// A drag coefficient subtracted from velocity each update, proportional
+    /// to the velocity itself (`vel -= vel * drag * dt`).
+    pub drag: f32,
+    /// Particle size (quad side length) at birth and at death.
+    pub size: (f32, f32),
+    /// Particle color at birth and at death.
+    pub color: ([f32; 4], [f32; 4]),
+    /// An optional sprite shared by every particle (sparks, smoke, ...).
+    pub texture: Option<Rc<Texture2d>>,
+
+    particles: Vec<Particle>,
+    spawn_accum: f32,
+}
+
+impl Emitter {
+    /// Create an emitter with no live particles yet.
+    pub fn new(
+        rate: f32,
+        lifetime: Range<f32>,
+        position: Range<[f32; 2]>,
+        velocity: Range<[f32; 2]>,
+    ) -> Emitter {
+        Emitter {
+            rate: rate,
+            lifetime: lifetime,
+            position: position,
+            velocity: velocity,
+            gravity: [0.0, 0.0],
+            drag: 0.0,
+            size: (0.02, 0.0),
+            color: ([1.0, 1.0, 1.0, 1.0], [1.0, 1.0, 1.0, 0.0]),
+            texture: None,
+            particles: Vec::new(),
+            spawn_accum: 0.0,
+        }
+    }
+
+    /// Apply constant acceleration (e.g. gravity) to every particle.
+    pub fn with_gravity(mut self, gravity: [f32; 2]) -> Emitter {
+        self.gravity = gravity;
+        self
+    }
+
+    /// Apply velocity-proportional drag to every particle.
+    pub fn with_drag(mut self, drag: f32) -> Emitter {
+        self.drag = drag;
+        self
+    }
+
+    /// Set the particle size at birth and at death; interpolated by age.
+    pub fn with_size(mut self, start: f32, end: f32) -> Emitter {
+        self.size = (start, end);
+        self
+    }
+
+    /// Set the particle color at birth and at death; interpolated by age.
+    pub fn with_color(mut self, start: [f32; 4], end: [f32; 4]) -> Emitter {
+        self.color = (start, end);
+        self
+    }
+
+    /// Give every particle a shared sprite texture.
+    pub fn with_texture(mut self, texture: Rc<Texture2d>) -> Emitter {
+        self.texture = Some(texture);
+        self
+    }
+
+    /// Advance the simulation by `dt`: integrate live particles, retire
+    /// expired ones, and spawn new ones to meet `rate`.
+    pub fn update(&mut self, dt: Duration) {
+        let dt = dt.as_secs_f32();
+
+        for particle in self.particles.iter_mut() {
+            particle.vel[0] += self.gravity[0] * dt;
+            particle.vel[1] += self.gravity[1] * dt;
+            let drag = (self.drag * dt).min(1.0);
+            particle.vel[0] -= particle.vel[0] * drag;
+            particle.vel[1] -= particle.vel[1] * drag;
+            particle.pos[0] += particle.vel[0] * dt;
+            particle.pos[1] += particle.vel[1] * dt;
+            particle.age += dt;
+        }
+        self.particles.retain(|p| p.age < p.lifetime);
+
+        let mut rng = rand::thread_rng();
+        self.spawn_accum += self.rate * dt;
+        while self.spawn_accum >= 1.0 {
+            self.spawn_accum -= 1.0;
+            self.particles.push(Particle {
+                pos: self.position.sample(&mut rng),
+                vel: self.velocity.sample(&mut rng),
+                age: 0.0,
+                lifetime: self.lifetime.sample(&mut rng),
+            });
+        }
+    }
+}
+
+impl Clone for Emitter {
+    fn clone(&self) -> Emitter {
+        Emitter {
+            rate: self.rate,
+            lifetime: self.lifetime,
+            position: self.position,
+            velocity: self.velocity,
+            gravity: self.gravity,
+            drag: self.drag,
+            size: self.size,
+            color: self.color,
+            texture: self.texture.clone(),
+            particles: self.particles.clone(),
+            spawn_accum: self.spawn_accum,
+        }
+    }
+}
+
+impl IntoIterator for Emitter {
+    type Item = RendTri;
+    type IntoIter = vec::IntoIter<RendTri>;
+    fn into_iter(self) -> Self::IntoIter {
+        let mut tris = Vec::with_capacity(self.particles.len() * 2);
+        for particle in &self.particles {
+            let t = particle.normalized_age();
+            let half = (self.size.0 + (self.size.1 - self.size.0) * t) * 0.5;
+            let color = lerp_color(self.color.0, self.color.1, t);
+            let [x, y] = particle.pos;
+
+            let quad: RendTri = Tri::new_colors(
+                [[x - half, y - half], [x + half, y - half], [x - half, y + half]],
+                [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]],
+                [color, color, color],
+            ).into();
+            tris.push(quad.map_texture(self.texture.clone()));
+
+            let quad: RendTri = Tri::new_colors(
+                [[x + half, y + half], [x - half, y + half], [x + half, y - half]],
+                [[1.0, 1.0], [0.0, 1.0], [1.0, 0.0]],
+                [color, color, color],
+            ).into();
+            tris.push(quad.map_texture(self.texture.clone()));
+        }
+        tris.into_iter()
+    }
+}
+
+fn lerp_color(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    [
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+        a[3] + (b[3] - a[3]) * t,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gen_range_or_equal_bounds_returns_min() {
+        let mut rng = rand::thread_rng();
+        assert_eq!(gen_range_or(&mut rng, 1.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn gen_range_or_inverted_bounds_returns_min() {
+        let mut rng = rand::thread_rng();
+        assert_eq!(gen_range_or(&mut rng, 2.0, 1.0), 2.0);
+    }
+
+    #[test]
+    fn range_sample_f32_fixed_value_does_not_panic() {
+        let mut rng = rand::thread_rng();
+        let range = Range { min: 5.0, max: 5.0 };
+        assert_eq!(range.sample(&mut rng), 5.0);
+    }
+
+    #[test]
+    fn range_sample_vec2_fixed_value_does_not_panic() {
+        let mut rng = rand::thread_rng();
+        let range = Range {
+            min: [0.0, 0.0],
+            max: [0.0, 0.0],
+        };
+        assert_eq!(range.sample(&mut rng), [0.0, 0.0]);
+    }
+}
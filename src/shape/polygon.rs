@@ -0,0 +1,290 @@
+use std::vec;
+
+use super::{RendTri, Tri};
+
+/// A filled, possibly concave polygon, triangulated by ear-clipping.
+///
+/// ## Example
+/// ```rust,no_run
+/// use nest::*;
+/// let mut app = Window::new("Example", 640, 480).unwrap();
+/// app.draw(Polygon(vec![[-0.5, -0.5], [0.5, -0.5], [0.0, 0.5]]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Polygon(pub Vec<[f32; 2]>);
+
+impl IntoIterator for Polygon {
+    type Item = RendTri;
+    type IntoIter = vec::IntoIter<RendTri>;
+    fn into_iter(self) -> Self::IntoIter {
+        triangulate(self.0).into_iter().map(|tri| Tri::new_pos(tri).into()).collect::<Vec<_>>().into_iter()
+    }
+}
+
+/// A single segment of a `Path`.
+#[derive(Copy, Clone, Debug)]
+pub enum Segment {
+    Line([f32; 2]),
+    Quadratic([f32; 2], [f32; 2]),
+    Cubic([f32; 2], [f32; 2], [f32; 2]),
+}
+
+/// A builder for an outline made of line and Bezier segments, flattened to a
+/// `Polygon` when iterated.
+///
+/// Curves are recursively subdivided until the midpoint of each sub-segment
+/// deviates from the straight line between its endpoints by less than
+/// `tolerance`, giving a flattening that's finer where the curve bends sharply
+/// and coarser where it's nearly straight.
+#[derive(Clone, Debug)]
+pub struct Path {
+    start: [f32; 2],
+    segments: Vec<Segment>,
+    tolerance: f32,
+}
+
+impl Path {
+    /// Begin a path at `start`, flattening curves to within `tolerance`
+    /// (in the same units as the path's points).
+    pub fn new(start: [f32; 2], tolerance: f32) -> Path {
+        Path {
+            start: start,
+            segments: Vec::new(),
+            tolerance: tolerance,
+        }
+    }
+
+    /// Add a straight line segment to `to`.
+    pub fn line_to(mut self, to: [f32; 2]) -> Path {
+        self.segments.push(Segment::Line(to));
+        self
+    }
+
+    /// Add a quadratic Bezier segment through `control` to `to`.
+    pub fn quad_to(mut self, control: [f32; 2], to: [f32; 2]) -> Path {
+        self.segments.push(Segment::Quadratic(control, to));
+        self
+    }
+
+    /// Add a cubic Bezier segment through `control1`/`control2` to `to`.
+    pub fn cubic_to(mut self, control1: [f32; 2], control2: [f32; 2], to: [f32; 2]) -> Path {
+        self.segments.push(Segment::Cubic(control1, control2, to));
+        self
+    }
+
+    /// Flatten every segment into a sequence of straight line points,
+    /// yielding a fillable `Polygon`.
+    pub fn close(self) -> Polygon {
+        let mut points = vec![self.start];
+        let mut cur = self.start;
+        for segment in self.segments {
+            match segment {
+                Segment::Line(to) => {
+                    points.push(to);
+                    cur = to;
+                }
+                Segment::Quadratic(control, to) => {
+                    flatten_quadratic(cur, control, to, self.tolerance, MAX_FLATTEN_DEPTH, &mut points);
+                    cur = to;
+                }
+                Segment::Cubic(c1, c2, to) => {
+                    flatten_cubic(cur, c1, c2, to, self.tolerance, MAX_FLATTEN_DEPTH, &mut points);
+                    cur = to;
+                }
+            }
+        }
+        Polygon(points)
+    }
+}
+
+/// Hard cap on recursive subdivision depth, so a non-positive or otherwise
+/// unreachable `tolerance` bails out instead of recursing until the stack
+/// overflows.
+const MAX_FLATTEN_DEPTH: u32 = 16;
+
+fn lerp(a: [f32; 2], b: [f32; 2], t: f32) -> [f32; 2] {
+    [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t]
+}
+
+fn deviation(a: [f32; 2], mid: [f32; 2], b: [f32; 2]) -> f32 {
+    let straight = lerp(a, b, 0.5);
+    let dx = mid[0] - straight[0];
+    let dy = mid[1] - straight[1];
+    (dx * dx + dy * dy).sqrt()
+}
+
+fn flatten_quadratic(
+    a: [f32; 2],
+    control: [f32; 2],
+    b: [f32; 2],
+    tolerance: f32,
+    depth: u32,
+    out: &mut Vec<[f32; 2]>,
+) {
+    let mid = lerp(lerp(a, control, 0.5), lerp(control, b, 0.5), 0.5);
+    if depth == 0 || deviation(a, mid, b) < tolerance {
+        out.push(b);
+        return;
+    }
+    let ac = lerp(a, control, 0.5);
+    let cb = lerp(control, b, 0.5);
+    flatten_quadratic(a, ac, mid, tolerance, depth - 1, out);
+    flatten_quadratic(mid, cb, b, tolerance, depth - 1, out);
+}
+
+fn flatten_cubic(
+    a: [f32; 2],
+    c1: [f32; 2],
+    c2: [f32; 2],
+    b: [f32; 2],
+    tolerance: f32,
+    depth: u32,
+    out: &mut Vec<[f32; 2]>,
+) {
+    let ab = lerp(a, c1, 0.5);
+    let bc = lerp(c1, c2, 0.5);
+    let cd = lerp(c2, b, 0.5);
+    let abbc = lerp(ab, bc, 0.5);
+    let bccd = lerp(bc, cd, 0.5);
+    let mid = lerp(abbc, bccd, 0.5);
+    if depth == 0 || deviation(a, mid, b) < tolerance {
+        out.push(b);
+        return;
+    }
+    flatten_cubic(a, ab, abbc, mid, tolerance, depth - 1, out);
+    flatten_cubic(mid, bccd, cd, b, tolerance, depth - 1, out);
+}
+
+fn signed_area(points: &[[f32; 2]]) -> f32 {
+    let mut area = 0.0;
+    for i in 0..points.len() {
+        let a = points[i];
+        let b = points[(i + 1) % points.len()];
+        area += a[0] * b[1] - b[0] * a[1];
+    }
+    area * 0.5
+}
+
+fn cross(o: [f32; 2], a: [f32; 2], b: [f32; 2]) -> f32 {
+    (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+}
+
+fn point_in_triangle(p: [f32; 2], a: [f32; 2], b: [f32; 2], c: [f32; 2]) -> bool {
+    let d1 = cross(a, b, p);
+    let d2 = cross(b, c, p);
+    let d3 = cross(c, a, p);
+    let has_neg = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_pos = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_neg && has_pos)
+}
+
+/// Triangulate a simple polygon outline by ear-clipping, returning one
+/// `[[f32; 2]; 3]` per triangle.
+fn triangulate(points: Vec<[f32; 2]>) -> Vec<[[f32; 2]; 3]> {
+    let mut tris = Vec::new();
+    if points.len() < 3 {
+        return tris;
+    }
+
+    // Positive area means counter-clockwise winding; an ear's corner must
+    // turn the same way as the polygon as a whole.
+    let ccw = signed_area(&points) > 0.0;
+    let mut ring = points;
+
+    while ring.len() > 3 {
+        let n = ring.len();
+        let mut clipped = false;
+        for i in 0..n {
+            let prev = ring[(i + n - 1) % n];
+            let cur = ring[i];
+            let next = ring[(i + 1) % n];
+
+            let turn = cross(prev, cur, next);
+            if ccw && turn <= 0.0 || !ccw && turn >= 0.0 {
+                // Reflex (or collinear/degenerate) corner; can't be an ear.
+                continue;
+            }
+
+            let is_ear = (0..n)
+                .filter(|&j| j != i && j != (i + n - 1) % n && j != (i + 1) % n)
+                .all(|j| !point_in_triangle(ring[j], prev, cur, next));
+
+            if is_ear {
+                if turn.abs() > ::std::f32::EPSILON {
+                    tris.push([prev, cur, next]);
+                }
+                ring.remove(i);
+                clipped = true;
+                break;
+            }
+        }
+        if !clipped {
+            // Degenerate polygon (e.g. all collinear); bail rather than loop forever.
+            break;
+        }
+    }
+
+    if ring.len() == 3 && cross(ring[0], ring[1], ring[2]).abs() > ::std::f32::EPSILON {
+        tris.push([ring[0], ring[1], ring[2]]);
+    }
+
+    tris
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triangulate_convex_square() {
+        let points = vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+        let tris = triangulate(points.clone());
+        assert_eq!(tris.len(), points.len() - 2);
+    }
+
+    #[test]
+    fn triangulate_concave_l_shape() {
+        let points = vec![
+            [0.0, 0.0],
+            [2.0, 0.0],
+            [2.0, 1.0],
+            [1.0, 1.0],
+            [1.0, 2.0],
+            [0.0, 2.0],
+        ];
+        let tris = triangulate(points.clone());
+        // Any valid triangulation of a simple n-gon has exactly n - 2 triangles.
+        assert_eq!(tris.len(), points.len() - 2);
+    }
+
+    #[test]
+    fn triangulate_collinear_degenerate_is_empty() {
+        let points = vec![[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]];
+        assert!(triangulate(points).is_empty());
+    }
+
+    #[test]
+    fn flatten_quadratic_zero_tolerance_is_bounded_by_depth() {
+        let mut points = Vec::new();
+        flatten_quadratic([0.0, 0.0], [1.0, 1.0], [2.0, 0.0], 0.0, MAX_FLATTEN_DEPTH, &mut points);
+        assert!(!points.is_empty());
+        assert!(points.len() <= (1usize << MAX_FLATTEN_DEPTH) + 1);
+    }
+
+    #[test]
+    fn flatten_cubic_zero_tolerance_is_bounded_by_depth() {
+        // Regression case that previously stack-overflowed with no depth cap.
+        let mut points = Vec::new();
+        flatten_cubic(
+            [0.0, 0.0],
+            [1.0, 1.0],
+            [-1.0, 1.0],
+            [0.0, 0.0],
+            0.0,
+            MAX_FLATTEN_DEPTH,
+            &mut points,
+        );
+        assert!(!points.is_empty());
+        assert!(points.len() <= (1usize << MAX_FLATTEN_DEPTH) + 1);
+    }
+}
@@ -0,0 +1,82 @@
+use cgm;
+use std::vec;
+
+use super::{RendTri, Tri};
+
+fn fan<F: Fn(f32) -> [f32; 2]>(center: [f32; 2], rim: F, segments: usize) -> Vec<RendTri> {
+    (0..segments)
+        .map(|i| {
+            let theta_a = i as f32 / segments as f32 * ::std::f32::consts::PI * 2.0;
+            let theta_b = (i + 1) as f32 / segments as f32 * ::std::f32::consts::PI * 2.0;
+            Tri::new_pos([center, rim(theta_a), rim(theta_b)]).into()
+        })
+        .collect()
+}
+
+/// A circle, drawn as a triangle fan of `segments` wedges.
+///
+/// ## Example
+/// ```rust,no_run
+/// use nest::*;
+/// let mut app = Window::new("Example", 640, 480).unwrap();
+/// app.draw(Circle([0.0, 0.0], 0.5, 32));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Circle(pub [f32; 2], pub f32, pub usize);
+
+impl IntoIterator for Circle {
+    type Item = RendTri;
+    type IntoIter = vec::IntoIter<RendTri>;
+    fn into_iter(self) -> Self::IntoIter {
+        let Circle(center, radius, segments) = self;
+        fan(
+            center,
+            |theta| [center[0] + theta.cos() * radius, center[1] + theta.sin() * radius],
+            segments,
+        ).into_iter()
+    }
+}
+
+/// An axis-aligned ellipse with separate x/y radii, drawn as a triangle fan
+/// of `segments` wedges.
+#[derive(Copy, Clone, Debug)]
+pub struct Ellipse(pub [f32; 2], pub [f32; 2], pub usize);
+
+impl IntoIterator for Ellipse {
+    type Item = RendTri;
+    type IntoIter = vec::IntoIter<RendTri>;
+    fn into_iter(self) -> Self::IntoIter {
+        let Ellipse(center, radii, segments) = self;
+        fan(
+            center,
+            |theta| [center[0] + theta.cos() * radii[0], center[1] + theta.sin() * radii[1]],
+            segments,
+        ).into_iter()
+    }
+}
+
+/// A regular polygon with `sides` sides, drawn as a triangle fan. This is the
+/// same construction as `Circle`, just intended for low segment counts where
+/// the facets are meant to be visible (triangles, pentagons, hexagons, ...).
+#[derive(Copy, Clone, Debug)]
+pub struct RegularPolygon(pub [f32; 2], pub f32, pub usize);
+
+impl IntoIterator for RegularPolygon {
+    type Item = RendTri;
+    type IntoIter = vec::IntoIter<RendTri>;
+    fn into_iter(self) -> Self::IntoIter {
+        Circle(self.0, self.1, self.2).into_iter()
+    }
+}
+
+/// Create a circle centered at `center` with the given `radius`, tessellated
+/// into `segments` wedges.
+pub fn circle<P: Into<cgm::Point2<f32>>>(center: P, radius: f32, segments: usize) -> Circle {
+    Circle(center.into().into(), radius, segments)
+}
+
+/// Create an ellipse centered at `center` with the given `radii` (x, y),
+/// tessellated into `segments` wedges.
+pub fn ellipse<P: Into<cgm::Point2<f32>>>(center: P, radii: [f32; 2], segments: usize) -> Ellipse {
+    Ellipse(center.into().into(), radii, segments)
+}
@@ -0,0 +1,253 @@
+use fontdue;
+use glium;
+use cgm;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::vec;
+
+use glium::backend::Facade;
+use glium::texture::{RawImage2d, Texture2d};
+
+use color::Color;
+use super::{RendTri, Tri};
+
+/// How many rasterization pixels a `Text`'s shape-space `size` (the quad
+/// height of one glyph) maps to. Used to pick a rasterization pixel size
+/// that roughly matches the glyph's actual on-screen resolution, rather than
+/// rasterizing every size from one fixed-resolution bitmap.
+const PIXELS_PER_UNIT: f32 = 512.0;
+
+/// Key identifying a single rasterized glyph: which character, at which
+/// (rounded) pixel size.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+struct GlyphKey {
+    ch: char,
+    px: u32,
+}
+
+/// Cached atlas placement and layout metrics for a single rasterized glyph.
+#[derive(Copy, Clone)]
+struct GlyphInfo {
+    /// UV rect within the atlas, as `(min, max)` in `[0, 1]` texture space.
+    uv_min: [f32; 2],
+    uv_max: [f32; 2],
+    /// Size of the rasterized glyph quad, in the same units as advance.
+    size: [f32; 2],
+    /// Offset of the glyph quad's bottom-left corner from the pen position.
+    bearing: [f32; 2],
+    /// Horizontal distance to advance the pen after drawing this glyph.
+    advance: f32,
+}
+
+/// A CPU-rasterized font backed by a shared glyph atlas texture.
+///
+/// Glyphs are rasterized lazily the first time they're requested at a given
+/// pixel size and packed into the atlas with a simple shelf packer: glyphs
+/// are placed left-to-right along a "shelf" of a fixed height, and a new
+/// shelf is opened below the previous one once a glyph doesn't fit. When the
+/// atlas runs out of room it is grown and every previously packed glyph is
+/// re-rasterized into the new texture.
+///
+/// A `Font` is meant to be owned by the `Window` and shared (via
+/// `Rc<RefCell<_>>`) with any `Text` shapes drawn against it.
+pub struct Font {
+    face: fontdue::Font,
+    atlas: Rc<Texture2d>,
+    atlas_size: u32,
+    glyphs: HashMap<GlyphKey, GlyphInfo>,
+    shelf_x: u32,
+    shelf_y: u32,
+    shelf_height: u32,
+}
+
+impl Font {
+    /// Load a font from TrueType/OpenType bytes and allocate its initial
+    /// (empty) atlas.
+    pub fn new<F: Facade>(facade: &F, bytes: &[u8]) -> Font {
+        let face = fontdue::Font::from_bytes(bytes, fontdue::FontSettings::default())
+            .expect("error: failed to parse font");
+        let atlas_size = 256;
+        let atlas = Texture2d::empty(facade, atlas_size, atlas_size)
+            .expect("error: failed to allocate glyph atlas");
+        Font {
+            face: face,
+            atlas: Rc::new(atlas),
+            atlas_size: atlas_size,
+            glyphs: HashMap::new(),
+            shelf_x: 0,
+            shelf_y: 0,
+            shelf_height: 0,
+        }
+    }
+
+    /// Look up the layout/atlas info for `ch` at `px` pixels tall, rasterizing
+    /// and packing it into the atlas first if this is the first time it's
+    /// been requested at this size.
+    fn glyph<F: Facade>(&mut self, facade: &F, ch: char, px: f32) -> GlyphInfo {
+        let key = GlyphKey {
+            ch: ch,
+            // Round to the nearest whole pixel so that near-identical sizes
+            // share a single cached rasterization.
+            px: px.round() as u32,
+        };
+        if let Some(&info) = self.glyphs.get(&key) {
+            return info;
+        }
+        let (metrics, coverage) = self.face.rasterize(ch, key.px as f32);
+        let info = self.pack(facade, metrics, &coverage);
+        self.glyphs.insert(key, info);
+        info
+    }
+
+    /// Pack a freshly rasterized glyph's coverage bitmap into the atlas,
+    /// opening a new shelf or growing the atlas if it doesn't fit.
+    fn pack<F: Facade>(&mut self, facade: &F, metrics: fontdue::Metrics, coverage: &[u8]) -> GlyphInfo {
+        let (w, h) = (metrics.width as u32, metrics.height as u32);
+
+        if self.shelf_x + w > self.atlas_size {
+            self.shelf_x = 0;
+            self.shelf_y += self.shelf_height;
+            self.shelf_height = 0;
+        }
+        if w > self.atlas_size || self.shelf_y + h > self.atlas_size {
+            self.grow(facade);
+            return self.pack(facade, metrics, coverage);
+        }
+
+        let rect = glium::Rect {
+            left: self.shelf_x,
+            bottom: self.shelf_y,
+            width: w,
+            height: h,
+        };
+        if w > 0 && h > 0 {
+            // Coverage is single-channel; expand it to the atlas's RGBA format.
+            let rgba: Vec<u8> = coverage.iter().flat_map(|&c| vec![255, 255, 255, c]).collect();
+            let image = RawImage2d::from_raw_rgba(rgba, (w, h));
+            self.atlas.main_level().write(rect, image);
+        }
+
+        let info = GlyphInfo {
+            uv_min: [
+                self.shelf_x as f32 / self.atlas_size as f32,
+                self.shelf_y as f32 / self.atlas_size as f32,
+            ],
+            uv_max: [
+                (self.shelf_x + w) as f32 / self.atlas_size as f32,
+                (self.shelf_y + h) as f32 / self.atlas_size as f32,
+            ],
+            size: [w as f32, h as f32],
+            bearing: [metrics.xmin as f32, metrics.ymin as f32],
+            advance: metrics.advance_width,
+        };
+
+        self.shelf_x += w;
+        self.shelf_height = self.shelf_height.max(h);
+
+        info
+    }
+
+    /// Double the atlas's side length and re-rasterize every glyph packed so
+    /// far into the new texture, resetting the shelf cursor.
+    fn grow<F: Facade>(&mut self, facade: &F) {
+        self.atlas_size *= 2;
+        self.atlas = Rc::new(
+            Texture2d::empty(facade, self.atlas_size, self.atlas_size)
+                .expect("error: failed to grow glyph atlas"),
+        );
+        self.shelf_x = 0;
+        self.shelf_y = 0;
+        self.shelf_height = 0;
+        let keys: Vec<GlyphKey> = self.glyphs.keys().cloned().collect();
+        self.glyphs.clear();
+        for key in keys {
+            let (metrics, coverage) = self.face.rasterize(key.ch, key.px as f32);
+            let info = self.pack(facade, metrics, &coverage);
+            self.glyphs.insert(key, info);
+        }
+    }
+}
+
+/// A string of text, rendered as a run of glyph quads sampled from a shared
+/// `Font` atlas.
+///
+/// ## Example
+/// ```rust,no_run
+/// use nest::*;
+/// let mut app = Window::new("Example", 640, 480).unwrap();
+/// let font = app.load_font("examples/font.ttf");
+/// app.draw(Text("hello".into(), font, 0.1).translate([-0.5, 0.0]));
+/// ```
+#[derive(Clone)]
+pub struct Text(pub String, pub Rc<RefCell<Font>>, pub f32);
+
+impl Text {
+    /// Set the color the text is drawn in. Defaults to white.
+    pub fn color<C: Into<Color>>(self, color: C) -> ColoredText {
+        ColoredText(self, color.into())
+    }
+}
+
+/// A `Text` shape paired with the color its glyph quads should be tinted.
+#[derive(Clone)]
+pub struct ColoredText(Text, Color);
+
+impl IntoIterator for Text {
+    type Item = RendTri;
+    type IntoIter = vec::IntoIter<RendTri>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.color(Color::WHITE).into_iter()
+    }
+}
+
+impl IntoIterator for ColoredText {
+    type Item = RendTri;
+    type IntoIter = vec::IntoIter<RendTri>;
+    fn into_iter(self) -> Self::IntoIter {
+        let ColoredText(Text(string, font, size), color) = self;
+        let atlas = font.borrow().atlas.clone();
+
+        let mut tris = Vec::with_capacity(string.chars().count() * 2);
+        let mut pen_x = 0.0f32;
+
+        for ch in string.chars() {
+            // `px` is the rasterization size in pixels; `size` is the quad's
+            // height in shape-space. Deriving `px` from `size` (rather than a
+            // fixed constant) means the glyph cache, which is keyed by
+            // `(glyph, pixel-size)`, rasterizes each distinct on-screen size
+            // at its own resolution instead of upscaling a single fixed
+            // bitmap and blurring large text.
+            let px = (size * PIXELS_PER_UNIT).max(1.0);
+            let info = {
+                let facade = font.borrow().atlas.get_context().clone();
+                font.borrow_mut().glyph(&facade, ch, px)
+            };
+            let scale = size / px;
+
+            let x0 = pen_x + info.bearing[0] * scale;
+            let y0 = info.bearing[1] * scale;
+            let x1 = x0 + info.size[0] * scale;
+            let y1 = y0 + info.size[1] * scale;
+
+            let quad: RendTri = Tri::new(
+                [[x0, y0], [x1, y0], [x0, y1]],
+                [info.uv_min, [info.uv_max[0], info.uv_min[1]], [info.uv_min[0], info.uv_max[1]]],
+                color,
+            ).into();
+            tris.push(quad.map_texture(atlas.clone()));
+
+            let quad: RendTri = Tri::new(
+                [[x1, y1], [x0, y1], [x1, y0]],
+                [info.uv_max, [info.uv_min[0], info.uv_max[1]], [info.uv_max[0], info.uv_min[1]]],
+                color,
+            ).into();
+            tris.push(quad.map_texture(atlas.clone()));
+
+            pen_x += info.advance * scale;
+        }
+
+        tris.into_iter()
+    }
+}